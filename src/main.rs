@@ -1,5 +1,217 @@
 use macroquad::prelude::*;
 
+// GLSL for the SDF render path (see `render_mode` below). Works in the shape's
+// local (rotation-inverted) frame: f(p) = pow(|x/r_a|^n + |y/r_b|^n, 1/n) - 1,
+// converted to an approximate signed distance via d = f / |grad f|, which lets
+// us anti-alias the edge (and the outline, via abs(d) - thickness/2) in a way
+// that's independent of the `steps` vertex count.
+const SDF_VERTEX_SHADER: &str = r#"#version 100
+attribute vec3 position;
+attribute vec2 texcoord;
+attribute vec4 color0;
+
+varying vec2 uv;
+
+uniform mat4 Model;
+uniform mat4 Projection;
+
+void main() {
+    gl_Position = Projection * Model * vec4(position, 1.0);
+    uv = texcoord;
+}
+"#;
+
+// The SDF path composites up to MAX_SHAPES superellipses (see `ShapeParams`
+// below). Per-shape uniforms are packed into vec4s (one component per shape)
+// rather than GLSL arrays, since they're set from Rust as plain tuples; the
+// shader unpacks them by component instead of dynamically indexing an array.
+const SDF_FRAGMENT_SHADER: &str = r#"#version 100
+#extension GL_OES_standard_derivatives : enable
+precision highp float;
+
+varying vec2 uv;
+
+uniform vec2 centre;
+uniform vec4 shape_r_a;
+uniform vec4 shape_r_b;
+uniform vec4 shape_n;
+uniform vec4 shape_offset_x;
+uniform vec4 shape_offset_y;
+uniform vec4 shape_rot_sin;
+uniform vec4 shape_rot_cos;
+uniform float shape_count;
+uniform vec3 op; // operator for each (shape[i], shape[i+1]) pair: 0 = union, 1 = intersection, 2 = subtraction
+uniform float blend_k;
+uniform float thickness;
+uniform float fill_mode; // > 0.5 => fill, else outline
+
+uniform float gradient_mode; // 0 = solid, 1 = linear, 2 = radial
+uniform vec2 gradient_axis; // unit direction of the linear gradient (tied to rotation)
+uniform float gradient_extent; // linear gradient half-length, in pixels
+uniform vec4 stop_pos; // up to 4 color stop positions, ascending, padded with the last real stop
+uniform vec3 stop_color0;
+uniform vec3 stop_color1;
+uniform vec3 stop_color2;
+uniform vec3 stop_color3;
+uniform vec4 stop_alpha;
+uniform float stop_count;
+
+// Superellipse implicit field for a single shape, in its local (rotation-inverted) frame.
+float field(vec2 p, float r_a, float r_b, float n, float offset_x, float offset_y, float rot_sin, float rot_cos) {
+    vec2 local_p = p - vec2(offset_x, offset_y);
+    vec2 local = vec2(
+        rot_cos * local_p.x + rot_sin * local_p.y,
+        -rot_sin * local_p.x + rot_cos * local_p.y
+    );
+    float ax = abs(local.x / r_a);
+    float ay = abs(local.y / r_b);
+    return pow(max(pow(ax, n) + pow(ay, n), 1e-8), 1.0 / n) - 1.0;
+}
+
+// Approximate signed distance for shape i: divide the field by its own
+// gradient magnitude so the result is in roughly screen-pixel units.
+float shape_distance(vec2 p, float r_a, float r_b, float n, float offset_x, float offset_y, float rot_sin, float rot_cos) {
+    float f = field(p, r_a, r_b, n, offset_x, offset_y, rot_sin, rot_cos);
+    vec2 grad = vec2(dFdx(f), dFdy(f));
+    return f / max(length(grad), 1e-6);
+}
+
+// Polynomial smooth-min (Quilez). k = 0 reduces to a hard min.
+float smin(float a, float b, float k) {
+    float h = clamp(0.5 + 0.5 * (b - a) / max(k, 1e-5), 0.0, 1.0);
+    return mix(b, a, h) - k * h * (1.0 - h);
+}
+
+// Combine two distance fields with the CSG operator encoded in `op_code`:
+// union = min(a,b), intersection = max(a,b), subtraction = max(a,-b), all
+// via the smooth-min so `blend_k` morphs between the two shapes.
+float combine(float a, float b, float op_code, float k) {
+    if (op_code < 0.5) {
+        return smin(a, b, k);
+    } else if (op_code < 1.5) {
+        return -smin(-a, -b, k);
+    } else {
+        return -smin(-a, b, k);
+    }
+}
+
+// Piecewise-linear sample of up to 4 ascending color stops at t in [0, 1].
+// Unused stops (count < 4) are padded with the last real stop's position, so
+// falling through to the final branch is always safe.
+vec4 gradient_sample(float t) {
+    t = clamp(t, 0.0, 1.0);
+    if (stop_count < 1.5 || t <= stop_pos.x) {
+        return vec4(stop_color0, stop_alpha.x);
+    }
+    if (stop_count < 2.5 || t <= stop_pos.y) {
+        float lt = clamp((t - stop_pos.x) / max(stop_pos.y - stop_pos.x, 1e-5), 0.0, 1.0);
+        return vec4(mix(stop_color0, stop_color1, lt), mix(stop_alpha.x, stop_alpha.y, lt));
+    }
+    if (stop_count < 3.5 || t <= stop_pos.z) {
+        float lt = clamp((t - stop_pos.y) / max(stop_pos.z - stop_pos.y, 1e-5), 0.0, 1.0);
+        return vec4(mix(stop_color1, stop_color2, lt), mix(stop_alpha.y, stop_alpha.z, lt));
+    }
+    if (t <= stop_pos.w) {
+        float lt = clamp((t - stop_pos.z) / max(stop_pos.w - stop_pos.z, 1e-5), 0.0, 1.0);
+        return vec4(mix(stop_color2, stop_color3, lt), mix(stop_alpha.z, stop_alpha.w, lt));
+    }
+    return vec4(stop_color3, stop_alpha.w);
+}
+
+void main() {
+    vec2 p = gl_FragCoord.xy - centre;
+
+    float d = shape_distance(p, shape_r_a.x, shape_r_b.x, shape_n.x, shape_offset_x.x, shape_offset_y.x, shape_rot_sin.x, shape_rot_cos.x);
+    if (shape_count > 1.5) {
+        float d1 = shape_distance(p, shape_r_a.y, shape_r_b.y, shape_n.y, shape_offset_x.y, shape_offset_y.y, shape_rot_sin.y, shape_rot_cos.y);
+        d = combine(d, d1, op.x, blend_k);
+    }
+    if (shape_count > 2.5) {
+        float d2 = shape_distance(p, shape_r_a.z, shape_r_b.z, shape_n.z, shape_offset_x.z, shape_offset_y.z, shape_rot_sin.z, shape_rot_cos.z);
+        d = combine(d, d2, op.y, blend_k);
+    }
+    if (shape_count > 3.5) {
+        float d3 = shape_distance(p, shape_r_a.w, shape_r_b.w, shape_n.w, shape_offset_x.w, shape_offset_y.w, shape_rot_sin.w, shape_rot_cos.w);
+        d = combine(d, d3, op.z, blend_k);
+    }
+
+    float w = 1.0;
+    float shape_d = fill_mode > 0.5 ? d : abs(d) - thickness * 0.5;
+    float coverage = 1.0 - smoothstep(-w, w, shape_d);
+
+    vec3 rgb = vec3(1.0);
+    float stop_a = 1.0;
+    // gradients only apply to fill (outline stays solid white, as before)
+    if (gradient_mode > 0.5 && fill_mode > 0.5) {
+        float t;
+        if (gradient_mode > 1.5) {
+            // radial: the field is -1 at the centre and 0 at shape 0's boundary, so f0 + 1 runs 0..1
+            float f0 = field(p, shape_r_a.x, shape_r_b.x, shape_n.x, shape_offset_x.x, shape_offset_y.x, shape_rot_sin.x, shape_rot_cos.x);
+            t = clamp(f0 + 1.0, 0.0, 1.0);
+        } else {
+            t = clamp(0.5 + 0.5 * dot(p, gradient_axis) / max(gradient_extent, 1e-3), 0.0, 1.0);
+        }
+        vec4 g = gradient_sample(t);
+        rgb = g.rgb;
+        stop_a = g.a;
+    }
+
+    gl_FragColor = vec4(rgb, coverage * stop_a);
+}
+"#;
+
+const MAX_SHAPES: usize = 4;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Operator {
+    Union,
+    Intersection,
+    Subtraction,
+}
+
+impl Operator {
+    fn next(self) -> Self {
+        match self {
+            Operator::Union => Operator::Intersection,
+            Operator::Intersection => Operator::Subtraction,
+            Operator::Subtraction => Operator::Union,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Operator::Union => "Union",
+            Operator::Intersection => "Intersection",
+            Operator::Subtraction => "Subtraction",
+        }
+    }
+
+    fn as_uniform(self) -> f32 {
+        match self {
+            Operator::Union => 0.0,
+            Operator::Intersection => 1.0,
+            Operator::Subtraction => 2.0,
+        }
+    }
+}
+
+// One superellipse in the scene. `offset` is relative to the window centre
+// so shapes can be moved apart for the boolean ops to be visible.
+#[derive(Clone, Copy)]
+struct ShapeParams {
+    r_a: f32,
+    r_b: f32,
+    n: f32,
+    offset: Vec2,
+    rotation_degrees: f32,
+}
+
+impl ShapeParams {
+    fn rotation_trig(&self) -> (f32, f32) {
+        (self.rotation_degrees * std::f32::consts::PI / 180.0).sin_cos()
+    }
+}
+
 struct Slider {
     label: String,
     value: f32,
@@ -51,42 +263,493 @@ impl Slider {
     }
 }
 
-fn render_quadrants<F>(
-    c: Vec2, 
-    steps: usize, 
-    vertex_buffer: &[Vec2], 
+#[derive(PartialEq)]
+enum RenderMode {
+    Vertices, // fan/line rasterization over the vertex buffer
+    Sdf,      // per-pixel signed-distance-field shader, AA'd, independent of `steps`
+}
+
+// Beyond this ratio (extended miter length / half-thickness) a miter join
+// falls back to a bevel, same convention as SVG's stroke-miterlimit.
+const MITER_LIMIT: f32 = 4.0;
+
+#[derive(Clone, Copy, PartialEq)]
+enum JoinStyle {
+    Miter,
+    Bevel,
+    Round,
+}
+
+impl JoinStyle {
+    fn next(self) -> Self {
+        match self {
+            JoinStyle::Miter => JoinStyle::Bevel,
+            JoinStyle::Bevel => JoinStyle::Round,
+            JoinStyle::Round => JoinStyle::Miter,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            JoinStyle::Miter => "Miter",
+            JoinStyle::Bevel => "Bevel",
+            JoinStyle::Round => "Round",
+        }
+    }
+}
+
+fn perp(d: Vec2) -> Vec2 {
+    vec2(-d.y, d.x)
+}
+
+// Walk all four quadrants into a single closed, correctly-wound polyline.
+// Quadrants alternate winding direction (mirroring one axis reverses it, both
+// axes preserves it), so every other quadrant has to be walked in reverse to
+// stay contiguous; we also drop points that coincide with the previous one
+// (shared axis points) rather than assuming a fixed index lines up exactly,
+// since that's where the fp seam issues mentioned elsewhere crop up.
+fn build_full_contour(
+    c: Vec2,
+    steps: usize,
+    vertex_buffer: &[Vec2],
     quadrants: &[(f32, f32)],
     rotation_matrix_trig_values: (f32, f32),
-    mut draw_callback: F
-) 
-where F: FnMut(Vec2, Vec2) 
-{
+) -> Vec<Vec2> {
+    let m = rotation_matrix_trig_values;
+    let mut contour = Vec::with_capacity(quadrants.len() * (steps + 1));
     for (sx, sy) in quadrants {
-        let v_0 = vertex_buffer[0];
-        let mut p_prev = Vec2::new(sx * v_0.x, sy * v_0.y);
-        // apply rotation, then offset by centre
-        let m = rotation_matrix_trig_values;
-        p_prev = Vec2::new(
-            m.1 * p_prev.x - m.0 * p_prev.y + c.x,
-            m.0 * p_prev.x + m.1 * p_prev.y + c.y,
-        );
-        for i in 1..=steps {
-            let v = vertex_buffer[i];
-
-            let mut p_curr = Vec2::new(sx * v.x, sy * v.y);
-            p_curr = Vec2::new(
-                m.1 * p_curr.x - m.0 * p_curr.y + c.x,
-                m.0 * p_curr.x + m.1 * p_curr.y + c.y,
+        let forward = sx * sy > 0.0;
+        let indices: Box<dyn Iterator<Item = usize>> = if forward {
+            Box::new(0..=steps)
+        } else {
+            Box::new((0..=steps).rev())
+        };
+        for idx in indices {
+            let v = vertex_buffer[idx];
+            let local = vec2(sx * v.x, sy * v.y);
+            let point = vec2(
+                m.1 * local.x - m.0 * local.y + c.x,
+                m.0 * local.x + m.1 * local.y + c.y,
+            );
+            if contour.last().is_some_and(|&last: &Vec2| last.distance(point) < 1e-4) {
+                continue;
+            }
+            contour.push(point);
+        }
+    }
+    // the last quadrant ends on the first point; drop it so the polygon closes implicitly
+    if contour.len() > 1 && contour[0].distance(contour[contour.len() - 1]) < 1e-4 {
+        contour.pop();
+    }
+    contour
+}
+
+// Emit the two join triangles/fan (one per side of the stroke) that fill the
+// gap a plain per-edge quad leaves at each vertex.
+fn emit_join(p: Vec2, dir_prev: Vec2, dir_cur: Vec2, halfw: f32, style: JoinStyle, triangles: &mut Vec<[Vec2; 3]>) {
+    for side in [1.0f32, -1.0] {
+        let n_prev = perp(dir_prev) * side;
+        let n_cur = perp(dir_cur) * side;
+        let prev_point = p + n_prev * halfw;
+        let cur_point = p + n_cur * halfw;
+        if prev_point.distance(cur_point) < 1e-4 {
+            continue; // segments are ~parallel, nothing to fill
+        }
+        match style {
+            JoinStyle::Bevel => {
+                triangles.push([p, prev_point, cur_point]);
+            }
+            JoinStyle::Miter => {
+                let bisector_sum = n_prev + n_cur;
+                let miter = if bisector_sum.length_squared() < 1e-8 {
+                    None
+                } else {
+                    let bisector = bisector_sum.normalize();
+                    let cos_half = bisector.dot(n_prev).max(1e-3);
+                    let miter_len = halfw / cos_half;
+                    (miter_len <= halfw * MITER_LIMIT).then(|| p + bisector * miter_len)
+                };
+                match miter {
+                    Some(miter_point) => {
+                        triangles.push([p, prev_point, miter_point]);
+                        triangles.push([p, miter_point, cur_point]);
+                    }
+                    None => triangles.push([p, prev_point, cur_point]), // past the miter limit: fall back to bevel
+                }
+            }
+            JoinStyle::Round => {
+                let segments = 6;
+                let start_angle = n_prev.y.atan2(n_prev.x);
+                let end_angle = n_cur.y.atan2(n_cur.x);
+                let mut delta = end_angle - start_angle;
+                while delta > std::f32::consts::PI {
+                    delta -= std::f32::consts::TAU;
+                }
+                while delta < -std::f32::consts::PI {
+                    delta += std::f32::consts::TAU;
+                }
+                let mut prev_arc_point = prev_point;
+                for s in 1..=segments {
+                    let t = s as f32 / segments as f32;
+                    let angle = start_angle + delta * t;
+                    let arc_point = p + vec2(angle.cos(), angle.sin()) * halfw;
+                    triangles.push([p, prev_arc_point, arc_point]);
+                    prev_arc_point = arc_point;
+                }
+            }
+        }
+    }
+}
+
+// Turn a closed polyline into a filled triangle list: one quad (two
+// triangles) per edge, plus join geometry at every vertex so corners don't
+// show the gaps a naive `draw_line`-per-segment stroke leaves behind.
+fn stroke_polygon(points: &[Vec2], thickness: f32, style: JoinStyle) -> Vec<[Vec2; 3]> {
+    let halfw = thickness / 2.0;
+    let count = points.len();
+    let mut triangles = Vec::with_capacity(count * 4);
+
+    for i in 0..count {
+        let p0 = points[i];
+        let p1 = points[(i + 1) % count];
+        let dir = (p1 - p0).normalize_or_zero();
+        if dir == Vec2::ZERO {
+            continue;
+        }
+        let normal = perp(dir);
+        let l0 = p0 + normal * halfw;
+        let r0 = p0 - normal * halfw;
+        let l1 = p1 + normal * halfw;
+        let r1 = p1 - normal * halfw;
+        triangles.push([l0, r0, r1]);
+        triangles.push([l0, r1, l1]);
+    }
+
+    for i in 0..count {
+        let prev = points[(i + count - 1) % count];
+        let curr = points[i];
+        let next = points[(i + 1) % count];
+        let dir_prev = (curr - prev).normalize_or_zero();
+        let dir_cur = (next - curr).normalize_or_zero();
+        if dir_prev == Vec2::ZERO || dir_cur == Vec2::ZERO {
+            continue;
+        }
+        emit_join(curr, dir_prev, dir_cur, halfw, style, &mut triangles);
+    }
+
+    triangles
+}
+
+const MAX_STOPS: usize = 4;
+
+// A small fixed palette to cycle a stop's color through, since there's no
+// color-picker widget in this UI (same spirit as the existing sliders).
+const PALETTE: [Color; 9] = [WHITE, RED, ORANGE, YELLOW, GREEN, SKYBLUE, BLUE, PURPLE, PINK];
+
+#[derive(PartialEq)]
+enum GradientMode {
+    Solid,
+    Linear, // axis tied to the shape's rotation (active shape on the vertex path, shape 0 on the SDF path)
+    Radial, // centred on that same shape, using its normalized field as t
+}
+
+impl GradientMode {
+    fn next(&self) -> Self {
+        match self {
+            GradientMode::Solid => GradientMode::Linear,
+            GradientMode::Linear => GradientMode::Radial,
+            GradientMode::Radial => GradientMode::Solid,
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            GradientMode::Solid => "Solid",
+            GradientMode::Linear => "Linear",
+            GradientMode::Radial => "Radial",
+        }
+    }
+
+    fn as_uniform(&self) -> f32 {
+        match self {
+            GradientMode::Solid => 0.0,
+            GradientMode::Linear => 1.0,
+            GradientMode::Radial => 2.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ColorStop {
+    position: f32, // 0..1 along the gradient
+    palette_index: usize,
+}
+
+impl ColorStop {
+    fn color(&self) -> Color {
+        PALETTE[self.palette_index]
+    }
+}
+
+// Sample the piecewise-linear gradient defined by `stops` (sorted ascending
+// by position) at `t`, clamping to the end stops outside [0, 1]. Mirrors
+// `gradient_sample` in the SDF fragment shader, for the triangle-fan path.
+fn eval_gradient(stops: &[ColorStop], t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    if t <= stops[0].position {
+        return stops[0].color();
+    }
+    if t >= stops[stops.len() - 1].position {
+        return stops[stops.len() - 1].color();
+    }
+    for pair in stops.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        if t <= b.position {
+            let span = (b.position - a.position).max(1e-5);
+            let local_t = (t - a.position) / span;
+            let ca = a.color();
+            let cb = b.color();
+            return Color::new(
+                ca.r + (cb.r - ca.r) * local_t,
+                ca.g + (cb.g - ca.g) * local_t,
+                ca.b + (cb.b - ca.b) * local_t,
+                ca.a + (cb.a - ca.a) * local_t,
             );
+        }
+    }
+    stops[stops.len() - 1].color()
+}
 
-            draw_callback(p_prev, p_curr);
-            p_prev = p_curr;
+#[derive(Clone, Copy, PartialEq)]
+enum FillRule {
+    EvenOdd,
+    NonZero,
+}
+
+impl FillRule {
+    fn next(self) -> Self {
+        match self {
+            FillRule::EvenOdd => FillRule::NonZero,
+            FillRule::NonZero => FillRule::EvenOdd,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            FillRule::EvenOdd => "Even-odd",
+            FillRule::NonZero => "Nonzero",
+        }
+    }
+}
+
+// Scanline-rasterize a closed polygon under the given winding rule, returning
+// (y, x_start, x_end) spans sampled every `step` in y across [y_min, y_max].
+// This replaces the old centre-fan fill, which over/under-covers once n < 1
+// makes the superellipse concave (or an extreme axis ratio flips a fan
+// triangle's winding) since the fan triangles then overlap incorrectly.
+fn scanline_fill(contour: &[Vec2], rule: FillRule, y_min: f32, y_max: f32, step: f32) -> Vec<(f32, f32, f32)> {
+    let count = contour.len();
+    let mut spans = Vec::new();
+    let mut y = y_min;
+    while y <= y_max {
+        // every edge the scanline crosses, with the direction it crosses in
+        let mut crossings: Vec<(f32, i32)> = Vec::new();
+        for i in 0..count {
+            let a = contour[i];
+            let b = contour[(i + 1) % count];
+            if (a.y <= y) != (b.y <= y) {
+                let t = (y - a.y) / (b.y - a.y);
+                let x = a.x + t * (b.x - a.x);
+                crossings.push((x, if b.y > a.y { 1 } else { -1 }));
+            }
         }
+        crossings.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        match rule {
+            FillRule::EvenOdd => {
+                for pair in crossings.chunks_exact(2) {
+                    spans.push((y, pair[0].0, pair[1].0));
+                }
+            }
+            FillRule::NonZero => {
+                let mut winding = 0;
+                let mut span_start = None;
+                for (x, dir) in &crossings {
+                    let was_inside = winding != 0;
+                    winding += dir;
+                    let is_inside = winding != 0;
+                    if !was_inside && is_inside {
+                        span_start = Some(*x);
+                    } else if was_inside && !is_inside {
+                        if let Some(start) = span_start.take() {
+                            spans.push((y, start, *x));
+                        }
+                    }
+                }
+            }
+        }
+        y += step;
+    }
+    spans
+}
+
+// "#rrggbb" for an SVG stop-color/fill attribute; alpha is written separately
+// as stop-opacity since SVG hex colors carry no alpha channel.
+fn color_to_hex(c: Color) -> String {
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        (c.r.clamp(0.0, 1.0) * 255.0).round() as u8,
+        (c.g.clamp(0.0, 1.0) * 255.0).round() as u8,
+        (c.b.clamp(0.0, 1.0) * 255.0).round() as u8,
+    )
+}
+
+// Emit the <linearGradient>/<radialGradient> def matching `gradient_mode` and
+// `stops`, returning its id for use as a `fill="url(#id)"` reference. Mirrors
+// the axis/field conventions `span_color`/`gradient_sample` use on screen:
+// linear runs along the shape's rotation angle, radial is centred on the
+// shape and stretched to its r_a/r_b aspect ratio. Coordinates are given
+// directly in `userSpaceOnUse` (the same absolute pixel space as the `<path>`
+// `d` attribute) rather than `objectBoundingBox`, since bounding-box units are
+// normalized to the path's rotated AABB and would skew the angle/aspect for
+// any rotated, non-square shape.
+fn export_gradient_def(mode: &GradientMode, stops: &[ColorStop], centre: Vec2, rotation_degrees: f32, r_a: f32, r_b: f32) -> (String, String) {
+    let id = "squircleGradient";
+    let mut stop_tags = String::new();
+    for stop in stops {
+        let c = stop.color();
+        stop_tags.push_str(&format!(
+            r#"<stop offset="{:.4}" stop-color="{}" stop-opacity="{:.3}"/>"#,
+            stop.position,
+            color_to_hex(c),
+            c.a,
+        ));
+    }
+    let def = match mode {
+        GradientMode::Linear => {
+            let theta = rotation_degrees.to_radians();
+            let axis_dir = vec2(theta.cos(), theta.sin());
+            let extent = r_a.max(r_b).max(1e-3);
+            let p0 = centre - axis_dir * extent;
+            let p1 = centre + axis_dir * extent;
+            format!(
+                r#"<linearGradient id="{id}" x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" gradientUnits="userSpaceOnUse">{stop_tags}</linearGradient>"#,
+                p0.x, p0.y, p1.x, p1.y,
+            )
+        }
+        GradientMode::Radial => {
+            // a circle of radius r_b, stretched to the shape's r_a/r_b aspect
+            // ratio and rotated about its own centre, both in user space
+            let aspect = r_a.max(1e-3) / r_b.max(1e-3);
+            let r = r_b.max(1e-3);
+            format!(
+                r#"<radialGradient id="{id}" cx="{cx:.2}" cy="{cy:.2}" r="{r:.2}" gradientTransform="translate({cx:.2} {cy:.2}) rotate({rotation_degrees}) scale({aspect} 1) translate({neg_cx:.2} {neg_cy:.2})" gradientUnits="userSpaceOnUse">{stop_tags}</radialGradient>"#,
+                cx = centre.x, cy = centre.y, neg_cx = -centre.x, neg_cy = -centre.y,
+            )
+        }
+        GradientMode::Solid => unreachable!("export_gradient_def is only called when gradient_mode != Solid"),
+    };
+    (format!("url(#{id})"), def)
+}
+
+// Write the closed contour out as a single <path>, reusing whatever traversal
+// produced it (build_full_contour), so the exported outline matches what's
+// on screen exactly rather than being refit from the raw superellipse formula.
+// When `gradient_mode` isn't Solid, the fill references a matching SVG
+// gradient def built from `color_stops` instead of a flat white fill, so the
+// export can be used to preview gradient button/card backgrounds.
+#[allow(clippy::too_many_arguments)]
+fn export_svg(
+    path: &str,
+    contour: &[Vec2],
+    width: f32,
+    height: f32,
+    thickness: f32,
+    filled: bool,
+    gradient_mode: &GradientMode,
+    color_stops: &[ColorStop],
+    centre: Vec2,
+    rotation_degrees: f32,
+    r_a: f32,
+    r_b: f32,
+) -> std::io::Result<()> {
+    let mut d = String::new();
+    for (i, p) in contour.iter().enumerate() {
+        let cmd = if i == 0 { "M" } else { "L" };
+        d.push_str(&format!("{cmd} {:.2} {:.2} ", p.x, p.y));
     }
+    d.push('Z');
+
+    let mut defs = String::new();
+    let fill_color = if *gradient_mode == GradientMode::Solid || !filled {
+        "white".to_string()
+    } else {
+        let mut stops = color_stops.to_vec();
+        stops.sort_by(|a, b| a.position.partial_cmp(&b.position).unwrap());
+        let (reference, def) = export_gradient_def(gradient_mode, &stops, centre, rotation_degrees, r_a, r_b);
+        defs = format!("<defs>{def}</defs>");
+        reference
+    };
+
+    let (fill_attr, stroke_attr) = if filled { (fill_color.as_str(), "none") } else { ("none", fill_color.as_str()) };
+    let svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">{defs}<path d="{d}" fill="{fill_attr}" stroke="{stroke_attr}" stroke-width="{thickness}"/></svg>"#,
+    );
+    std::fs::write(path, svg)
 }
 
 #[macroquad::main("Squircle (superellipse) drawer")]
 async fn main() {
+    let sdf_material = load_material(
+        ShaderSource::Glsl {
+            vertex: SDF_VERTEX_SHADER,
+            fragment: SDF_FRAGMENT_SHADER,
+        },
+        MaterialParams {
+            uniforms: vec![
+                UniformDesc::new("centre", UniformType::Float2),
+                UniformDesc::new("shape_r_a", UniformType::Float4),
+                UniformDesc::new("shape_r_b", UniformType::Float4),
+                UniformDesc::new("shape_n", UniformType::Float4),
+                UniformDesc::new("shape_offset_x", UniformType::Float4),
+                UniformDesc::new("shape_offset_y", UniformType::Float4),
+                UniformDesc::new("shape_rot_sin", UniformType::Float4),
+                UniformDesc::new("shape_rot_cos", UniformType::Float4),
+                UniformDesc::new("shape_count", UniformType::Float1),
+                UniformDesc::new("op", UniformType::Float3),
+                UniformDesc::new("blend_k", UniformType::Float1),
+                UniformDesc::new("thickness", UniformType::Float1),
+                UniformDesc::new("fill_mode", UniformType::Float1),
+                UniformDesc::new("gradient_mode", UniformType::Float1),
+                UniformDesc::new("gradient_axis", UniformType::Float2),
+                UniformDesc::new("gradient_extent", UniformType::Float1),
+                UniformDesc::new("stop_pos", UniformType::Float4),
+                UniformDesc::new("stop_color0", UniformType::Float3),
+                UniformDesc::new("stop_color1", UniformType::Float3),
+                UniformDesc::new("stop_color2", UniformType::Float3),
+                UniformDesc::new("stop_color3", UniformType::Float3),
+                UniformDesc::new("stop_alpha", UniformType::Float4),
+                UniformDesc::new("stop_count", UniformType::Float1),
+            ],
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let mut render_mode = RenderMode::Vertices;
+
+    // the scene: one shape to start with, growable up to MAX_SHAPES
+    let mut shapes: Vec<ShapeParams> = vec![ShapeParams {
+        r_a: screen_width() / 4.0,
+        r_b: screen_width() / 4.0,
+        n: 4.0,
+        offset: Vec2::ZERO,
+        rotation_degrees: 0.0,
+    }];
+    // operators[i] combines shapes[i] with shapes[i + 1]
+    let mut operators: Vec<Operator> = Vec::new();
+    let mut active_shape: usize = 0;
+
     let quadrants = [
             (1.0, 1.0),
             (-1.0, 1.0),  
@@ -108,23 +771,85 @@ async fn main() {
     let mut thickness_slider = Slider::new("Thickness", 2.0, 0.1, 40.0, 20.0, 160.0);
     let mut steps_slider = Slider::new("Steps", 25.0, 1.0, 50.0, 20.0, 200.0);
     let mut rotation_slider = Slider::new("Rotation", 0.0, 0.0, 180.0, 20.0, 240.0);
-    
+    let mut cx_slider = Slider::new("Centre X (offset)", 0.0, -screen_width() / 2.0, screen_width() / 2.0, 20.0, 280.0);
+    let mut cy_slider = Slider::new("Centre Y (offset)", 0.0, -screen_height() / 2.0, screen_height() / 2.0, 20.0, 320.0);
+    let mut blend_k_slider = Slider::new("Blend (k)", 0.0, 0.0, 100.0, 20.0, 360.0);
+    let mut stop_position_slider = Slider::new("Stop position", 0.0, 0.0, 1.0, 20.0, 400.0);
+
     let mut fill = false; // whether to fill the shape or just draw the outline
+    let mut join_style = JoinStyle::Miter; // outline-mode stroke join, cycled with J
+    let mut fill_rule = FillRule::NonZero; // fill-mode winding rule, cycled with R
+
+    let mut gradient_mode = GradientMode::Solid; // cycled with G, fill-only
+    let mut color_stops: Vec<ColorStop> = vec![
+        ColorStop { position: 0.0, palette_index: 0 }, // WHITE
+        ColorStop { position: 1.0, palette_index: 6 }, // BLUE
+    ];
+    let mut active_stop: usize = 0;
 
     let mut steps: usize = 25; // we need to cast steps to usize often, so we'll keep it around
     
     // plot the shape using macroquad
     loop {
         // take inputs
+            // the sliders always edit `shapes[active_shape]`; add/remove/cycle
+            // keys below swap which shape that is and reload its values in.
+            let mut active_shape_changed = false;
+
+            // add a shape (cloned from the active one) and make it active
+            if is_key_pressed(KeyCode::N) && shapes.len() < MAX_SHAPES {
+                shapes.push(shapes[active_shape]);
+                operators.push(Operator::Union);
+                active_shape = shapes.len() - 1;
+                active_shape_changed = true;
+            }
+            // remove the active shape, along with the operator that paired it in
+            if is_key_pressed(KeyCode::Backspace) && shapes.len() > 1 {
+                shapes.remove(active_shape);
+                if !operators.is_empty() {
+                    operators.remove(active_shape.min(operators.len() - 1));
+                }
+                active_shape = active_shape.min(shapes.len() - 1);
+                active_shape_changed = true;
+            }
+            // cycle which shape the sliders edit
+            if is_key_pressed(KeyCode::LeftBracket) {
+                active_shape = (active_shape + shapes.len() - 1) % shapes.len();
+                active_shape_changed = true;
+            }
+            if is_key_pressed(KeyCode::RightBracket) {
+                active_shape = (active_shape + 1) % shapes.len();
+                active_shape_changed = true;
+            }
+            // cycle the operator that combines the active shape with the next one
+            if is_key_pressed(KeyCode::O) && active_shape < operators.len() {
+                operators[active_shape] = operators[active_shape].next();
+            }
+
+            if active_shape_changed {
+                let s = shapes[active_shape];
+                r_a_slider.value = s.r_a;
+                r_b_slider.value = s.r_b;
+                n_slider.value = s.n;
+                rotation_slider.value = s.rotation_degrees;
+                cx_slider.value = s.offset.x;
+                cy_slider.value = s.offset.y;
+                rotation_matrix_trig_values = s.rotation_trig();
+                vertices_need_recalculation = true;
+            }
+
             // all these update would require recalculating the vertex buffer
             vertices_need_recalculation =
                 vertices_need_recalculation || // this is here to ensure we recalc on first frame
-                r_a_slider.update() || 
-                r_b_slider.update() || 
-                n_slider.update() || 
+                r_a_slider.update() ||
+                r_b_slider.update() ||
+                n_slider.update() ||
                 steps_slider.update();
 
             thickness_slider.update();
+            cx_slider.update();
+            cy_slider.update();
+            blend_k_slider.update();
             // set rotation
             if rotation_slider.update() {
                 let rotation_degrees = rotation_slider.value;
@@ -135,6 +860,63 @@ async fn main() {
             if is_key_pressed(KeyCode::Space) {
                 fill = !fill;
             }
+            // toggle between the vertex-based rasterizer and the SDF shader path
+            if is_key_pressed(KeyCode::Tab) {
+                render_mode = match render_mode {
+                    RenderMode::Vertices => RenderMode::Sdf,
+                    RenderMode::Sdf => RenderMode::Vertices,
+                };
+            }
+            // cycle the outline join style
+            if is_key_pressed(KeyCode::J) {
+                join_style = join_style.next();
+            }
+            // cycle the fill winding rule
+            if is_key_pressed(KeyCode::R) {
+                fill_rule = fill_rule.next();
+            }
+
+            // cycle fill gradient mode
+            if is_key_pressed(KeyCode::G) {
+                gradient_mode = gradient_mode.next();
+            }
+            // add a color stop (cloned from the active one) and make it active
+            if is_key_pressed(KeyCode::C) && color_stops.len() < MAX_STOPS {
+                color_stops.push(color_stops[active_stop]);
+                active_stop = color_stops.len() - 1;
+                stop_position_slider.value = color_stops[active_stop].position;
+            }
+            // remove the active stop (at least 2 must remain)
+            if is_key_pressed(KeyCode::X) && color_stops.len() > 2 {
+                color_stops.remove(active_stop);
+                active_stop = active_stop.min(color_stops.len() - 1);
+                stop_position_slider.value = color_stops[active_stop].position;
+            }
+            // cycle which stop the position slider and color cycling edit
+            if is_key_pressed(KeyCode::Comma) {
+                active_stop = (active_stop + color_stops.len() - 1) % color_stops.len();
+                stop_position_slider.value = color_stops[active_stop].position;
+            }
+            if is_key_pressed(KeyCode::Period) {
+                active_stop = (active_stop + 1) % color_stops.len();
+                stop_position_slider.value = color_stops[active_stop].position;
+            }
+            // cycle the active stop's color through the palette
+            if is_key_pressed(KeyCode::V) {
+                color_stops[active_stop].palette_index = (color_stops[active_stop].palette_index + 1) % PALETTE.len();
+            }
+            if stop_position_slider.update() {
+                color_stops[active_stop].position = stop_position_slider.value;
+            }
+
+            // write the (possibly just-edited) slider values back into the active shape
+            shapes[active_shape] = ShapeParams {
+                r_a: r_a_slider.value,
+                r_b: r_b_slider.value,
+                n: n_slider.value,
+                offset: vec2(cx_slider.value, cy_slider.value),
+                rotation_degrees: rotation_slider.value,
+            };
 
         if vertices_need_recalculation {
             let r_a = r_a_slider.value;
@@ -165,18 +947,179 @@ async fn main() {
 
         clear_background(DARKGRAY);
         // draw the shape by connecting the vertices
-        let centre: Vec2 = vec2(screen_width() / 2.0, screen_height() / 2.0);
-
-        // draw all the quadrants
-        // using a generic F allows us to pass a closure
-        if fill {
-            render_quadrants(centre, steps, &vertex_buffer, &quadrants, rotation_matrix_trig_values, |prev, curr| {
-                draw_triangle(centre, prev, curr, WHITE);
-            });
-        } else {
-            render_quadrants(centre, steps, &vertex_buffer, &quadrants, rotation_matrix_trig_values, |prev, curr| {
-                draw_line(prev.x, prev.y, curr.x, curr.y, thickness_slider.value, WHITE);
-            });
+        let window_centre: Vec2 = vec2(screen_width() / 2.0, screen_height() / 2.0);
+        // the vertex path only ever draws the active shape, offset from the window centre
+        let centre: Vec2 = window_centre + shapes[active_shape].offset;
+
+        // gradient stops sorted ascending by position, shared by both render paths
+        let mut sorted_stops = color_stops.clone();
+        sorted_stops.sort_by(|a, b| a.position.partial_cmp(&b.position).unwrap());
+
+        // draw the shape, either by fanning/lining the vertex buffer (active shape
+        // only) or via the SDF shader, which composites every shape in `shapes`.
+        match render_mode {
+            RenderMode::Vertices => {
+                if fill {
+                    // scanline-fill the full contour under the chosen winding rule,
+                    // rather than fanning triangles from the centre: the fan
+                    // over/under-covers once n < 1 makes the shape concave (or an
+                    // extreme axis ratio flips a fan triangle's orientation).
+                    let contour = build_full_contour(centre, steps, &vertex_buffer, &quadrants, rotation_matrix_trig_values);
+                    let y_min = contour.iter().map(|p| p.y).fold(f32::INFINITY, f32::min);
+                    let y_max = contour.iter().map(|p| p.y).fold(f32::NEG_INFINITY, f32::max);
+                    let spans = scanline_fill(&contour, fill_rule, y_min, y_max, 1.0);
+
+                    let r_a = shapes[active_shape].r_a;
+                    let r_b = shapes[active_shape].r_b;
+                    let n = shapes[active_shape].n;
+                    let axis_dir = vec2(rotation_matrix_trig_values.1, rotation_matrix_trig_values.0);
+                    let extent = r_a.max(r_b).max(1e-3);
+                    let span_color = |p: Vec2| -> Color {
+                        if gradient_mode == GradientMode::Solid {
+                            return WHITE;
+                        }
+                        let t = match gradient_mode {
+                            GradientMode::Linear => 0.5 + 0.5 * (p - centre).dot(axis_dir) / extent,
+                            GradientMode::Radial => {
+                                let rel = p - centre;
+                                let m = rotation_matrix_trig_values;
+                                let local = vec2(m.1 * rel.x + m.0 * rel.y, -m.0 * rel.x + m.1 * rel.y);
+                                let ax = (local.x / r_a).abs();
+                                let ay = (local.y / r_b).abs();
+                                (ax.powf(n) + ay.powf(n)).powf(1.0 / n)
+                            }
+                            GradientMode::Solid => 0.0, // unreachable: handled above
+                        };
+                        eval_gradient(&sorted_stops, t)
+                    };
+
+                    // sample/interpolate color along each span instead of once at its
+                    // midpoint: a single sample per row flattens the linear gradient
+                    // entirely for a centred, unrotated shape (every row's midpoint
+                    // sits on the axis, so t is 0.5 everywhere) and bands the radial
+                    // gradient, since both vary with x within a row.
+                    const GRADIENT_STEP: f32 = 2.0;
+                    for (y, x0, x1) in spans {
+                        if gradient_mode == GradientMode::Solid {
+                            draw_line(x0, y, x1, y, 1.0, WHITE);
+                            continue;
+                        }
+                        let mut x = x0;
+                        while x < x1 {
+                            let next = (x + GRADIENT_STEP).min(x1);
+                            let color = span_color(vec2((x + next) / 2.0, y));
+                            draw_line(x, y, next, y, 1.0, color);
+                            x = next;
+                        }
+                    }
+                } else {
+                    // stroke the full contour as a filled triangle mesh with proper
+                    // joins, instead of disconnected per-segment draw_line calls,
+                    // so corners don't show gaps at high thickness / low steps.
+                    let contour = build_full_contour(centre, steps, &vertex_buffer, &quadrants, rotation_matrix_trig_values);
+                    for tri in stroke_polygon(&contour, thickness_slider.value, join_style) {
+                        draw_triangle(tri[0], tri[1], tri[2], WHITE);
+                    }
+                }
+            }
+            RenderMode::Sdf => {
+                // pack up to MAX_SHAPES shapes into the vec4 uniforms, padding unused
+                // slots with shape 0's values (the shader ignores slots past shape_count)
+                let mut r_a = [shapes[0].r_a; MAX_SHAPES];
+                let mut r_b = [shapes[0].r_b; MAX_SHAPES];
+                let mut n = [shapes[0].n; MAX_SHAPES];
+                let mut offset_x = [shapes[0].offset.x; MAX_SHAPES];
+                let mut offset_y = [shapes[0].offset.y; MAX_SHAPES];
+                let mut rot_sin = [0.0f32; MAX_SHAPES];
+                let mut rot_cos = [1.0f32; MAX_SHAPES];
+                for (i, shape) in shapes.iter().enumerate() {
+                    let (sin, cos) = shape.rotation_trig();
+                    r_a[i] = shape.r_a;
+                    r_b[i] = shape.r_b;
+                    n[i] = shape.n;
+                    offset_x[i] = shape.offset.x;
+                    offset_y[i] = shape.offset.y;
+                    rot_sin[i] = sin;
+                    rot_cos[i] = cos;
+                }
+                let mut op = [Operator::Union.as_uniform(); 3];
+                for (i, operator) in operators.iter().enumerate().take(3) {
+                    op[i] = operator.as_uniform();
+                }
+
+                sdf_material.set_uniform("centre", (window_centre.x, window_centre.y));
+                sdf_material.set_uniform("shape_r_a", (r_a[0], r_a[1], r_a[2], r_a[3]));
+                sdf_material.set_uniform("shape_r_b", (r_b[0], r_b[1], r_b[2], r_b[3]));
+                sdf_material.set_uniform("shape_n", (n[0], n[1], n[2], n[3]));
+                sdf_material.set_uniform("shape_offset_x", (offset_x[0], offset_x[1], offset_x[2], offset_x[3]));
+                sdf_material.set_uniform("shape_offset_y", (offset_y[0], offset_y[1], offset_y[2], offset_y[3]));
+                sdf_material.set_uniform("shape_rot_sin", (rot_sin[0], rot_sin[1], rot_sin[2], rot_sin[3]));
+                sdf_material.set_uniform("shape_rot_cos", (rot_cos[0], rot_cos[1], rot_cos[2], rot_cos[3]));
+                sdf_material.set_uniform("shape_count", shapes.len() as f32);
+                sdf_material.set_uniform("op", (op[0], op[1], op[2]));
+                sdf_material.set_uniform("blend_k", blend_k_slider.value);
+                sdf_material.set_uniform("thickness", thickness_slider.value);
+                sdf_material.set_uniform("fill_mode", if fill { 1.0f32 } else { 0.0f32 });
+
+                // radial gradients are centred on shape 0; linear runs along its rotation axis
+                let mut stop_pos = [1.0f32; MAX_STOPS];
+                let mut stop_rgb = [(1.0f32, 1.0f32, 1.0f32); MAX_STOPS];
+                let mut stop_a = [1.0f32; MAX_STOPS];
+                for (i, stop) in sorted_stops.iter().enumerate().take(MAX_STOPS) {
+                    let c = stop.color();
+                    stop_pos[i] = stop.position;
+                    stop_rgb[i] = (c.r, c.g, c.b);
+                    stop_a[i] = c.a;
+                }
+                for i in sorted_stops.len()..MAX_STOPS {
+                    stop_pos[i] = stop_pos[sorted_stops.len() - 1];
+                    stop_rgb[i] = stop_rgb[sorted_stops.len() - 1];
+                    stop_a[i] = stop_a[sorted_stops.len() - 1];
+                }
+
+                sdf_material.set_uniform("gradient_mode", gradient_mode.as_uniform());
+                sdf_material.set_uniform("gradient_axis", (rot_cos[0], rot_sin[0]));
+                sdf_material.set_uniform("gradient_extent", r_a[0].max(r_b[0]));
+                sdf_material.set_uniform("stop_pos", (stop_pos[0], stop_pos[1], stop_pos[2], stop_pos[3]));
+                sdf_material.set_uniform("stop_color0", stop_rgb[0]);
+                sdf_material.set_uniform("stop_color1", stop_rgb[1]);
+                sdf_material.set_uniform("stop_color2", stop_rgb[2]);
+                sdf_material.set_uniform("stop_color3", stop_rgb[3]);
+                sdf_material.set_uniform("stop_alpha", (stop_a[0], stop_a[1], stop_a[2], stop_a[3]));
+                sdf_material.set_uniform("stop_count", sorted_stops.len() as f32);
+
+                gl_use_material(&sdf_material);
+                draw_rectangle(0.0, 0.0, screen_width(), screen_height(), WHITE);
+                gl_use_default_material();
+            }
+        }
+
+        // export: S writes the active shape as a vector SVG, P the current frame as a PNG
+        if is_key_pressed(KeyCode::S) {
+            let contour = build_full_contour(centre, steps, &vertex_buffer, &quadrants, rotation_matrix_trig_values);
+            let active = shapes[active_shape];
+            if let Err(e) = export_svg(
+                "squircle.svg",
+                &contour,
+                screen_width(),
+                screen_height(),
+                thickness_slider.value,
+                fill,
+                &gradient_mode,
+                &sorted_stops,
+                centre,
+                active.rotation_degrees,
+                active.r_a,
+                active.r_b,
+            ) {
+                eprintln!("failed to export squircle.svg: {e}");
+            }
+        }
+        if is_key_pressed(KeyCode::P) {
+            // exports at the current window resolution; `Image` has no resize,
+            // so there's no cheap way to export at an arbitrary target size
+            // without re-rendering the whole scene into an offscreen target
+            get_screen_data().export_png("squircle.png");
         }
 
         // draw sliders
@@ -186,9 +1129,43 @@ async fn main() {
             thickness_slider.draw();
             steps_slider.draw();
             rotation_slider.draw();
+            cx_slider.draw();
+            cy_slider.draw();
+            blend_k_slider.draw();
+            stop_position_slider.draw();
         // draw fill mode text
             let fill_text = if fill { "Fill: ON (press SPACE to toggle)" } else { "Fill: OFF (press SPACE to toggle)" };
-            draw_text(fill_text, 20.0, 280.0, 20.0, WHITE);
+            draw_text(fill_text, 20.0, 440.0, 20.0, WHITE);
+            let mode_text = match render_mode {
+                RenderMode::Vertices => "Render: Vertices (press TAB for SDF)",
+                RenderMode::Sdf => "Render: SDF (press TAB for Vertices)",
+            };
+            draw_text(mode_text, 20.0, 465.0, 20.0, WHITE);
+        // draw scene/shape info: active shape, shape count, and the operator
+        // joining it to the next one (N adds, Backspace removes, [ ] cycle, O cycles op)
+            let shape_text = format!(
+                "Shape {}/{} ([ ] to cycle, N to add, Backspace to remove)",
+                active_shape + 1,
+                shapes.len()
+            );
+            draw_text(&shape_text, 20.0, 490.0, 20.0, WHITE);
+            if active_shape < operators.len() {
+                let op_text = format!("Operator to shape {}: {} (O to cycle)", active_shape + 2, operators[active_shape].label());
+                draw_text(&op_text, 20.0, 515.0, 20.0, WHITE);
+            }
+            let join_text = format!("Outline join: {} (J to cycle)", join_style.label());
+            draw_text(&join_text, 20.0, 540.0, 20.0, WHITE);
+            let fill_rule_text = format!("Fill rule: {} (R to cycle)", fill_rule.label());
+            draw_text(&fill_rule_text, 20.0, 565.0, 20.0, WHITE);
+            let gradient_text = format!("Fill gradient: {} (G to cycle)", gradient_mode.label());
+            draw_text(&gradient_text, 20.0, 590.0, 20.0, WHITE);
+            let stop_text = format!(
+                "Stop {}/{} (, . to cycle, C to add, X to remove, V to cycle color)",
+                active_stop + 1,
+                color_stops.len()
+            );
+            draw_text(&stop_text, 20.0, 615.0, 20.0, WHITE);
+            draw_text("S: export SVG, P: export PNG", 20.0, 640.0, 20.0, WHITE);
         next_frame().await
 
     }
@@ -205,4 +1182,21 @@ async fn main() {
 // - add rotation to the shape
 // - make slider struct logic neater
 // - only recalculated the vertex buffer if a relevant slider value has been changed
-// - change all f64 to f32 where possible as the precision isnt needed
\ No newline at end of file
+// - change all f64 to f32 where possible as the precision isnt needed
+// - add an SDF-based render path (TAB to toggle) that shades the superellipse
+//   per-pixel with analytic anti-aliasing, independent of the Steps slider
+// - extend the SDF path to a small scene of shapes (N/Backspace/[/]) combined
+//   pairwise with union/intersection/subtraction (O to cycle) and a smooth
+//   Blend (k) slider that morphs between them
+// - replace the outline mode's per-segment draw_line calls with a proper
+//   stroker: walk the full contour and fill a triangle mesh with miter/bevel/
+//   round joins (J to cycle), fixing the corner gaps at high thickness
+// - add linear/radial gradient fills (G to cycle, ,/. to pick a stop, C/X to
+//   add/remove, V to cycle its color) via the `stop_*` uniforms on the SDF
+//   path and a per-span color lookup on the vertex path
+// - add S/P key bindings to export the active shape's contour to SVG and the
+//   current frame to PNG
+// - replace the centre-fan fill with a scanline rasterizer over the full
+//   contour (R cycles even-odd/nonzero), since the fan over/under-covers
+//   once n < 1 makes the shape concave or an extreme axis ratio flips a
+//   fan triangle's winding
\ No newline at end of file